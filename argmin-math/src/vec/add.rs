@@ -0,0 +1,23 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminAdd;
+
+macro_rules! make_add {
+    ($t:ty) => {
+        #[cfg(not(feature = "simd"))]
+        impl ArgminAdd<Vec<$t>, Vec<$t>> for Vec<$t> {
+            fn add(&self, other: &Vec<$t>) -> Vec<$t> {
+                assert_eq!(self.len(), other.len());
+                self.iter().zip(other.iter()).map(|(a, b)| a + b).collect()
+            }
+        }
+    };
+}
+
+make_add!(f32);
+make_add!(f64);
@@ -16,6 +16,8 @@ mod norm;
 mod random;
 mod scaledadd;
 mod scaledsub;
+#[cfg(feature = "simd")]
+mod simd;
 mod sub;
 mod transpose;
 mod zero;
@@ -31,6 +33,8 @@ pub use norm::*;
 pub use random::*;
 pub use scaledadd::*;
 pub use scaledsub::*;
+#[cfg(feature = "simd")]
+pub use simd::*;
 pub use sub::*;
 pub use transpose::*;
 pub use zero::*;
@@ -0,0 +1,22 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminDot, ArgminNorm};
+
+macro_rules! make_norm {
+    ($t:ty) => {
+        #[cfg(not(feature = "simd"))]
+        impl ArgminNorm<$t> for Vec<$t> {
+            fn norm(&self) -> $t {
+                ArgminDot::dot(self, self).sqrt()
+            }
+        }
+    };
+}
+
+make_norm!(f32);
+make_norm!(f64);
@@ -0,0 +1,26 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminScaledAdd;
+
+macro_rules! make_scaledadd {
+    ($t:ty) => {
+        #[cfg(not(feature = "simd"))]
+        impl ArgminScaledAdd<Vec<$t>, $t, Vec<$t>> for Vec<$t> {
+            fn scaled_add(&self, factor: &$t, vec: &Vec<$t>) -> Vec<$t> {
+                assert_eq!(self.len(), vec.len());
+                self.iter()
+                    .zip(vec.iter())
+                    .map(|(a, b)| a + factor * b)
+                    .collect()
+            }
+        }
+    };
+}
+
+make_scaledadd!(f32);
+make_scaledadd!(f64);
@@ -0,0 +1,23 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSub;
+
+macro_rules! make_sub {
+    ($t:ty) => {
+        #[cfg(not(feature = "simd"))]
+        impl ArgminSub<Vec<$t>, Vec<$t>> for Vec<$t> {
+            fn sub(&self, other: &Vec<$t>) -> Vec<$t> {
+                assert_eq!(self.len(), other.len());
+                self.iter().zip(other.iter()).map(|(a, b)| a - b).collect()
+            }
+        }
+    };
+}
+
+make_sub!(f32);
+make_sub!(f64);
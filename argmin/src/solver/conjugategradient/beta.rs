@@ -19,13 +19,37 @@ use argmin_math::{ArgminDot, ArgminNorm, ArgminSub};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
+/// Outcome of a [`NLCGBetaUpdate::update`] call.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum NLCGBeta<F> {
+    /// A regular, finite beta value
+    Value(F),
+    /// The update's denominator was (numerically) singular; the caller should treat
+    /// this as `beta = 0` and may want to record a restart.
+    Degenerate,
+}
+
+impl<F> NLCGBeta<F>
+where
+    F: ArgminFloat,
+{
+    /// Returns the beta value, or `0` if the update was [`NLCGBeta::Degenerate`].
+    pub fn unwrap_or_zero(self) -> F {
+        match self {
+            NLCGBeta::Value(beta) => beta,
+            NLCGBeta::Degenerate => F::from_f64(0.0).unwrap(),
+        }
+    }
+}
+
 /// Common interface for beta update methods (Nonlinear-CG)
 pub trait NLCGBetaUpdate<G, P, F>: SerializeAlias {
     /// Update beta
     /// Parameter 1: \nabla f_k
     /// Parameter 2: \nabla f_{k+1}
     /// Parameter 3: p_k
-    fn update(&self, nabla_f_k: &G, nabla_f_k_p_1: &G, p_k: &P) -> F;
+    fn update(&self, nabla_f_k: &G, nabla_f_k_p_1: &G, p_k: &P) -> NLCGBeta<F>;
 }
 
 /// Fletcher and Reeves (FR) method
@@ -47,8 +71,8 @@ where
     G: ArgminDot<G, F>,
     F: ArgminFloat,
 {
-    fn update(&self, dfk: &G, dfk1: &G, _pk: &P) -> F {
-        dfk1.dot(dfk1) / dfk.dot(dfk)
+    fn update(&self, dfk: &G, dfk1: &G, _pk: &P) -> NLCGBeta<F> {
+        NLCGBeta::Value(dfk1.dot(dfk1) / dfk.dot(dfk))
     }
 }
 
@@ -71,9 +95,9 @@ where
     G: ArgminDot<G, F> + ArgminSub<G, G> + ArgminNorm<F>,
     F: ArgminFloat,
 {
-    fn update(&self, dfk: &G, dfk1: &G, _pk: &P) -> F {
+    fn update(&self, dfk: &G, dfk1: &G, _pk: &P) -> NLCGBeta<F> {
         let dfk_norm_sq = dfk.norm().powi(2);
-        dfk1.dot(&dfk1.sub(dfk)) / dfk_norm_sq
+        NLCGBeta::Value(dfk1.dot(&dfk1.sub(dfk)) / dfk_norm_sq)
     }
 }
 
@@ -96,10 +120,10 @@ where
     G: ArgminDot<G, F> + ArgminSub<G, G> + ArgminNorm<F>,
     F: ArgminFloat,
 {
-    fn update(&self, dfk: &G, dfk1: &G, _pk: &P) -> F {
+    fn update(&self, dfk: &G, dfk1: &G, _pk: &P) -> NLCGBeta<F> {
         let dfk_norm_sq = dfk.norm().powi(2);
         let beta = dfk1.dot(&dfk1.sub(dfk)) / dfk_norm_sq;
-        F::from_f64(0.0).unwrap().max(beta)
+        NLCGBeta::Value(F::from_f64(0.0).unwrap().max(beta))
     }
 }
 
@@ -122,9 +146,123 @@ where
     G: ArgminDot<G, F> + ArgminDot<P, F> + ArgminSub<G, G>,
     F: ArgminFloat,
 {
-    fn update(&self, dfk: &G, dfk1: &G, pk: &P) -> F {
+    fn update(&self, dfk: &G, dfk1: &G, pk: &P) -> NLCGBeta<F> {
         let d = dfk1.sub(dfk);
-        dfk1.dot(&d) / d.dot(pk)
+        NLCGBeta::Value(dfk1.dot(&d) / d.dot(pk))
+    }
+}
+
+/// Dai and Yuan (DY) method
+///
+/// TODO: Reference
+#[derive(Default, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct DaiYuan {}
+
+impl DaiYuan {
+    /// Constructor
+    pub fn new() -> Self {
+        DaiYuan {}
+    }
+}
+
+impl<G, P, F> NLCGBetaUpdate<G, P, F> for DaiYuan
+where
+    G: ArgminDot<G, F> + ArgminDot<P, F> + ArgminSub<G, G>,
+    F: ArgminFloat,
+{
+    fn update(&self, dfk: &G, dfk1: &G, pk: &P) -> NLCGBeta<F> {
+        let yk = dfk1.sub(dfk);
+        let denom = yk.dot(pk);
+        if denom.abs() < F::epsilon() {
+            return NLCGBeta::Degenerate;
+        }
+        NLCGBeta::Value(dfk1.dot(dfk1) / denom)
+    }
+}
+
+/// Liu and Storey (LS) method
+///
+/// TODO: Reference
+#[derive(Default, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct LiuStorey {}
+
+impl LiuStorey {
+    /// Constructor
+    pub fn new() -> Self {
+        LiuStorey {}
+    }
+}
+
+impl<G, P, F> NLCGBetaUpdate<G, P, F> for LiuStorey
+where
+    G: ArgminDot<G, F> + ArgminDot<P, F> + ArgminSub<G, G>,
+    F: ArgminFloat,
+{
+    fn update(&self, dfk: &G, dfk1: &G, pk: &P) -> NLCGBeta<F> {
+        let yk = dfk1.sub(dfk);
+        let denom = dfk.dot(pk);
+        if denom.abs() < F::epsilon() {
+            return NLCGBeta::Degenerate;
+        }
+        NLCGBeta::Value(-dfk1.dot(&yk) / denom)
+    }
+}
+
+/// Hager and Zhang (CG_DESCENT) method
+///
+/// TODO: Reference
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct HagerZhang<F> {
+    /// Lower truncation parameter `eta`
+    eta: F,
+}
+
+impl<F> Default for HagerZhang<F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> Self {
+        HagerZhang {
+            eta: F::from_f64(0.01).unwrap(),
+        }
+    }
+}
+
+impl<F> HagerZhang<F>
+where
+    F: ArgminFloat,
+{
+    /// Constructor
+    pub fn new() -> Self {
+        HagerZhang::default()
+    }
+
+    /// Set the lower truncation parameter `eta` (default: `0.01`)
+    pub fn with_eta(mut self, eta: F) -> Self {
+        self.eta = eta;
+        self
+    }
+}
+
+impl<G, P, F> NLCGBetaUpdate<G, P, F> for HagerZhang<F>
+where
+    G: ArgminDot<G, F> + ArgminDot<P, F> + ArgminSub<G, G> + ArgminNorm<F>,
+    P: ArgminNorm<F>,
+    F: ArgminFloat,
+{
+    fn update(&self, dfk: &G, dfk1: &G, pk: &P) -> NLCGBeta<F> {
+        let yk = dfk1.sub(dfk);
+        let dk_dot_yk = yk.dot(pk);
+        if dk_dot_yk.abs() < F::epsilon() {
+            return NLCGBeta::Degenerate;
+        }
+        let yk_norm_sq = yk.dot(&yk);
+        let beta_n = (yk.dot(dfk1) - F::from_f64(2.0).unwrap() * yk_norm_sq * dfk1.dot(pk) / dk_dot_yk) / dk_dot_yk;
+        let eta_k = -F::from_f64(1.0).unwrap() / (pk.norm() * self.eta.min(dfk.norm()));
+        NLCGBeta::Value(beta_n.max(eta_k))
     }
 }
 
@@ -137,4 +275,7 @@ mod tests {
     test_trait_impl!(polak_ribiere, PolakRibiere);
     test_trait_impl!(polak_ribiere_plus, PolakRibierePlus);
     test_trait_impl!(hestenes_stiefel, HestenesStiefel);
+    test_trait_impl!(dai_yuan, DaiYuan);
+    test_trait_impl!(liu_storey, LiuStorey);
+    test_trait_impl!(hager_zhang, HagerZhang<f64>);
 }
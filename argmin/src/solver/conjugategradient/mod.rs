@@ -0,0 +1,253 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Nonlinear Conjugate Gradient method
+//!
+//! TODO: Proper documentation.
+//!
+//! # Reference
+//!
+//! \[0\] Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+//! Springer. ISBN 0-387-30303-0.
+
+mod beta;
+
+pub use beta::*;
+
+use crate::core::{ArgminFloat, CostFunction, Error, Gradient, IterState, Problem, Solver, State, KV};
+use argmin_math::{ArgminDot, ArgminMul, ArgminNorm, ArgminScaledAdd};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// A line search used by [`NonlinearConjugateGradient`] to determine how far to step
+/// along a given descent direction.
+///
+/// Given the current iterate, a descent direction, and the cost/gradient at the current
+/// iterate, returns the new iterate together with its cost and gradient.
+pub trait NLCGLineSearch<O, P, G, F> {
+    /// Perform the line search
+    fn search(
+        &mut self,
+        problem: &mut Problem<O>,
+        param: &P,
+        direction: &P,
+        cost: F,
+        grad: &G,
+    ) -> Result<(P, F, G), Error>;
+}
+
+/// Indicates which restart criterion (if any) triggered a restart in a given iteration
+/// of [`NonlinearConjugateGradient`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum NLCGRestart {
+    /// No restart was necessary
+    No,
+    /// Periodic restart after the configured number of iterations
+    Periodic,
+    /// Powell's non-conjugacy test indicated a loss of conjugacy
+    Powell,
+    /// The computed search direction was no longer a descent direction
+    Descent,
+    /// The beta-update denominator was (numerically) singular
+    Degenerate,
+}
+
+/// # Nonlinear Conjugate Gradient method
+///
+/// Computes the descent direction `d_{k+1} = -g_{k+1} + beta * d_k`, where `beta` is
+/// computed via a pluggable [`NLCGBetaUpdate`], and performs a line search along
+/// `d_{k+1}` to determine the next iterate.
+///
+/// To avoid stalling or losing conjugacy, the search direction is reset to the
+/// steepest-descent direction (`beta = 0`) whenever one of the following restart
+/// criteria fires:
+///
+/// * a periodic restart every `restart_iters` iterations (see [`restart_iters`](
+///   `NonlinearConjugateGradient::restart_iters`), disabled by default; Nocedal & Wright
+///   recommend setting it to the problem dimension),
+/// * Powell's non-conjugacy test (see [`restart_orthogonality`](
+///   `NonlinearConjugateGradient::restart_orthogonality`)), enabled by default with
+///   `nu = 0.2`, which restarts when `|<g_{k+1}, g_k>| >= nu * <g_{k+1}, g_{k+1}>`,
+/// * a loss-of-descent test (see [`restart_condition`](
+///   `NonlinearConjugateGradient::restart_condition`)), which restarts when
+///   `<g_{k+1}, d_{k+1}> >= -sigma * ||g_{k+1}|| * ||d_{k+1}||`.
+///
+/// Additionally, whenever [`NLCGBetaUpdate::update`] reports [`NLCGBeta::Degenerate`]
+/// (or, defensively, a non-finite value) because its denominator was numerically
+/// singular, `beta` is replaced with `0` rather than propagating the degeneracy, which
+/// degrades the iteration gracefully to steepest descent instead of diverging.
+///
+/// The restart criterion which triggered (if any) is reported for diagnostics via the
+/// `"restart"` key in the [`KV`] returned alongside the state.
+///
+/// # Reference
+///
+/// TODO: Reference
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct NonlinearConjugateGradient<P, L, B, F> {
+    /// Beta update method
+    beta_method: B,
+    /// Line search
+    linesearch: L,
+    /// Number of iterations after which a restart is forced (disabled if `None`)
+    restart_iters: Option<u64>,
+    /// Powell's non-conjugacy test parameter `nu` (disabled if `None`)
+    restart_orthogonality: Option<F>,
+    /// Loss-of-descent test parameter `sigma` (disabled if `None`)
+    restart_condition: Option<F>,
+    /// Search direction `d_k`
+    p_prev: Option<P>,
+    /// Restart criterion that triggered in the most recent iteration, if any
+    restart: NLCGRestart,
+}
+
+impl<P, L, B, F> NonlinearConjugateGradient<P, L, B, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`NonlinearConjugateGradient`].
+    ///
+    /// Takes a `linesearch` and a `beta_method` (see [`NLCGBetaUpdate`]). Powell's
+    /// non-conjugacy test is enabled by default with `nu = 0.2`, the standard choice,
+    /// so the solver restarts automatically without further configuration; the
+    /// periodic and loss-of-descent restarts are disabled by default since they
+    /// require problem-specific parameters.
+    pub fn new(linesearch: L, beta_method: B) -> Self {
+        NonlinearConjugateGradient {
+            beta_method,
+            linesearch,
+            restart_iters: None,
+            restart_orthogonality: Some(F::from_f64(0.2).unwrap()),
+            restart_condition: None,
+            p_prev: None,
+            restart: NLCGRestart::No,
+        }
+    }
+
+    /// Enable a periodic restart every `iters` iterations (disabled by default).
+    /// Nocedal & Wright recommend setting this to the problem dimension.
+    pub fn restart_iters(mut self, iters: u64) -> Self {
+        self.restart_iters = Some(iters);
+        self
+    }
+
+    /// Set Powell's non-conjugacy test parameter `nu` (enabled by default with
+    /// `nu = 0.2`, the standard choice).
+    pub fn restart_orthogonality(mut self, nu: F) -> Self {
+        self.restart_orthogonality = Some(nu);
+        self
+    }
+
+    /// Enable the loss-of-descent test with parameter `sigma` (disabled by default).
+    pub fn restart_condition(mut self, sigma: F) -> Self {
+        self.restart_condition = Some(sigma);
+        self
+    }
+}
+
+impl<O, P, G, L, B, F> Solver<O, IterState<P, G, (), (), F>> for NonlinearConjugateGradient<P, L, B, F>
+where
+    O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
+    P: Clone + ArgminNorm<F> + ArgminScaledAdd<P, F, P>,
+    G: ArgminDot<G, F> + ArgminDot<P, F> + ArgminMul<F, P> + ArgminNorm<F>,
+    L: NLCGLineSearch<O, P, G, F>,
+    B: NLCGBetaUpdate<G, P, F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Nonlinear Conjugate Gradient";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let param = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            "`NonlinearConjugateGradient` requires an initial parameter vector. Please \
+             provide an initial guess via `Executor`'s `configure` method."
+        ))?;
+        let cost = problem.cost(param)?;
+        let grad = problem.gradient(param)?;
+
+        self.p_prev = Some(grad.mul(&F::from_f64(-1.0).unwrap()));
+
+        Ok((state.cost(cost).gradient(grad), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let iter = state.get_iter();
+        let xk = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`NonlinearConjugateGradient`: Parameter vector in state not set."
+        ))?;
+        let cost = state.get_cost();
+        let dfk = state.take_gradient().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`NonlinearConjugateGradient`: Gradient in state not set."
+        ))?;
+        let pk = self.p_prev.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`NonlinearConjugateGradient`: Search direction not set."
+        ))?;
+
+        let (xk1, cost1, dfk1) = self.linesearch.search(problem, &xk, &pk, cost, &dfk)?;
+
+        self.restart = NLCGRestart::No;
+
+        let mut beta = match self.beta_method.update(&dfk, &dfk1, &pk) {
+            NLCGBeta::Value(beta) if beta.is_finite() => beta,
+            _ => {
+                self.restart = NLCGRestart::Degenerate;
+                F::from_f64(0.0).unwrap()
+            }
+        };
+
+        if self.restart == NLCGRestart::No {
+            if let Some(nu) = self.restart_orthogonality {
+                if dfk1.dot(&dfk).abs() >= nu * dfk1.dot(&dfk1) {
+                    beta = F::from_f64(0.0).unwrap();
+                    self.restart = NLCGRestart::Powell;
+                }
+            }
+        }
+
+        if self.restart == NLCGRestart::No {
+            if let Some(iters) = self.restart_iters {
+                if iters > 0 && (iter + 1) % iters == 0 {
+                    beta = F::from_f64(0.0).unwrap();
+                    self.restart = NLCGRestart::Periodic;
+                }
+            }
+        }
+
+        let neg_dfk1 = dfk1.mul(&F::from_f64(-1.0).unwrap());
+        let mut pk1 = neg_dfk1.scaled_add(&beta, &pk);
+
+        if self.restart == NLCGRestart::No {
+            if let Some(sigma) = self.restart_condition {
+                if dfk1.dot(&pk1) >= -sigma * dfk1.norm() * pk1.norm() {
+                    pk1 = neg_dfk1;
+                    beta = F::from_f64(0.0).unwrap();
+                    self.restart = NLCGRestart::Descent;
+                }
+            }
+        }
+
+        self.p_prev = Some(pk1);
+
+        Ok((
+            state.param(xk1).cost(cost1).gradient(dfk1),
+            Some(kv!("restart" => self.restart; "beta" => beta;)),
+        ))
+    }
+}
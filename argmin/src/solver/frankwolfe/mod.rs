@@ -0,0 +1,204 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Frank-Wolfe (conditional gradient) method
+//!
+//! TODO: Proper documentation.
+//!
+//! # Reference
+//!
+//! \[0\] Martin Jaggi (2013). Revisiting Frank-Wolfe: Projection-Free Sparse Convex
+//! Optimization. Proceedings of the 30th International Conference on Machine Learning
+//! (ICML 2013).
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, IterState, Problem, Solver, State,
+    TerminationReason, KV,
+};
+use argmin_math::{ArgminDot, ArgminScaledAdd, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Linear minimization oracle (LMO) over a convex, compact feasible set `C`.
+///
+/// Given the gradient at the current iterate, the oracle returns a vertex
+/// `s_k = argmin_{s in C} <gradient, s>` of `C`. This is typically far cheaper to
+/// evaluate than a projection onto `C`, which is the main motivation for using the
+/// Frank-Wolfe method over projected-gradient methods.
+pub trait LinearMinimizationOracle<G, P> {
+    /// Returns `s = argmin_{s in C} <gradient, s>`.
+    fn minimize(&self, gradient: &G) -> P;
+}
+
+/// Step size strategy used by [`FrankWolfe`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum FWLineSearch<F> {
+    /// Classical open-loop step size `gamma_k = 2 / (k + 2)`, which evaluates the cost
+    /// function only once per iteration, at the new iterate.
+    Open,
+    /// Backtracking line search over `gamma in (0, 1]`, starting at the open-loop
+    /// `gamma_k = 2 / (k + 2)` and shrunk by `decrease` until the cost at the trial
+    /// point is no worse than the cost at the current iterate, or until `gamma` drops
+    /// below `1e-10`, in which case that last trial point is accepted.
+    Backtracking {
+        /// Shrinkage factor applied to `gamma` on each backtracking step
+        decrease: F,
+    },
+}
+
+/// # Frank-Wolfe (conditional gradient) method
+///
+/// A first-order method for minimization over a convex, compact feasible set `C` which
+/// avoids the (potentially expensive) projection step of projected-gradient methods by
+/// instead relying on a [`LinearMinimizationOracle`] to find the vertex of `C` most
+/// aligned with the negative gradient. The next iterate is a convex combination of the
+/// current iterate and that vertex, so iterates stay feasible for all `k` without ever
+/// projecting.
+///
+/// The duality gap `g_k = <grad, x_k - s_k>` is a certified upper bound on the primal
+/// suboptimality `f(x_k) - f^*` and is used as the stopping criterion: the solver
+/// terminates once `g_k <= tol`.
+///
+/// # Reference
+///
+/// TODO: Reference
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct FrankWolfe<LP, F> {
+    /// Linear minimization oracle
+    lp: LP,
+    /// Step size strategy
+    gamma_strategy: FWLineSearch<F>,
+    /// Tolerance on the duality gap used as stopping criterion
+    tol: F,
+}
+
+impl<LP, F> FrankWolfe<LP, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`FrankWolfe`].
+    ///
+    /// Takes a [`LinearMinimizationOracle`] for the feasible set `C`. Defaults to the
+    /// classical open-loop step size `gamma_k = 2 / (k + 2)` and a duality-gap
+    /// tolerance of `1e-6`.
+    pub fn new(lp: LP) -> Self {
+        FrankWolfe {
+            lp,
+            gamma_strategy: FWLineSearch::Open,
+            tol: F::from_f64(1e-6).unwrap(),
+        }
+    }
+
+    /// Use a backtracking line search for the step size instead of the open-loop
+    /// default, shrinking `gamma` by `decrease` on each backtracking step.
+    ///
+    /// `decrease` must lie in `(0, 1)`; otherwise an error is returned. With
+    /// `decrease >= 1`, `gamma` would never shrink towards the `gamma < 1e-10`
+    /// backtracking guard, and with `decrease <= 0` it would turn non-positive.
+    pub fn with_backtracking(mut self, decrease: F) -> Result<Self, Error> {
+        if decrease <= F::from_f64(0.0).unwrap() || decrease >= F::from_f64(1.0).unwrap() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`FrankWolfe`: decrease parameter must be in (0, 1)."
+            ));
+        }
+        self.gamma_strategy = FWLineSearch::Backtracking { decrease };
+        Ok(self)
+    }
+
+    /// Set the tolerance on the duality gap used as stopping criterion (default:
+    /// `1e-6`).
+    pub fn with_tolerance(mut self, tol: F) -> Self {
+        self.tol = tol;
+        self
+    }
+}
+
+impl<O, LP, P, G, F> Solver<O, IterState<P, G, (), (), F>> for FrankWolfe<LP, F>
+where
+    O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
+    P: Clone + ArgminSub<P, P> + ArgminScaledAdd<P, F, P>,
+    G: ArgminDot<P, F>,
+    LP: LinearMinimizationOracle<G, P>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Frank-Wolfe method";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let param = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            "`FrankWolfe` requires an initial parameter vector. Please provide an \
+             initial guess via `Executor`'s `configure` method."
+        ))?;
+        let cost = problem.cost(param)?;
+        let grad = problem.gradient(param)?;
+
+        Ok((state.cost(cost).gradient(grad), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let iter = state.get_iter();
+        let xk = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`FrankWolfe`: Parameter vector in state not set."
+        ))?;
+        let grad = state.take_gradient().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`FrankWolfe`: Gradient in state not set."
+        ))?;
+
+        let sk = self.lp.minimize(&grad);
+        let gap = grad.dot(&xk.sub(&sk));
+
+        if gap <= self.tol {
+            let state = state
+                .param(xk)
+                .gradient(grad)
+                .terminate_with(TerminationReason::SolverConverged);
+            return Ok((state, Some(kv!("gap" => gap;))));
+        }
+
+        let dk = sk.sub(&xk);
+        let gamma_open = F::from_f64(2.0).unwrap() / (F::from_u64(iter).unwrap() + F::from_f64(2.0).unwrap());
+
+        let (x_next, cost, gamma) = match self.gamma_strategy {
+            FWLineSearch::Open => {
+                let x_next = xk.scaled_add(&gamma_open, &dk);
+                let cost = problem.cost(&x_next)?;
+                (x_next, cost, gamma_open)
+            }
+            FWLineSearch::Backtracking { decrease } => {
+                let cost_xk = state.get_cost();
+                let mut gamma = gamma_open;
+                loop {
+                    let x_trial = xk.scaled_add(&gamma, &dk);
+                    let cost_trial = problem.cost(&x_trial)?;
+                    if cost_trial <= cost_xk || gamma < F::from_f64(1e-10).unwrap() {
+                        break (x_trial, cost_trial, gamma);
+                    }
+                    gamma *= decrease;
+                }
+            }
+        };
+
+        let grad_next = problem.gradient(&x_next)?;
+
+        let state = state.param(x_next).cost(cost).gradient(grad_next);
+
+        Ok((state, Some(kv!("gamma" => gamma; "gap" => gap;))))
+    }
+}